@@ -1,4 +1,137 @@
 
+/// SplitPattern generalises the matcher accepted by `split_before`/`split_after`
+/// (and friends) beyond plain closures, mirroring std's string `Pattern` API.
+/// It is implemented for `FnMut(&T) -> bool` closures, for the common
+/// primitive element types so a single value can be matched directly (e.g.
+/// `slice.split_after(2u8)` instead of `slice.split_after(|v: &u8| *v == 2)`), and
+/// for `Subsequence` to split on a multi-element separator.
+pub trait SplitPattern<T> {
+    /// Returns `true` if `item` completes a match of this pattern. Patterns
+    /// that span more than one element (see `Subsequence`) track how much of
+    /// the pattern has matched so far on `self` between calls.
+    fn matches(&mut self, item: &T) -> bool;
+
+    /// Number of elements consumed by one match, counting back from the item
+    /// that made `matches` return `true`. Single-element patterns (the
+    /// default) consume just that one item.
+    fn match_len(&self) -> usize {
+        1
+    }
+}
+
+impl <T, F> SplitPattern<T> for F
+where
+    F: FnMut(&T) -> bool,
+{
+    fn matches(&mut self, item: &T) -> bool {
+        (self)(item)
+    }
+}
+
+// A blanket `impl<T: PartialEq> SplitPattern<T> for T` would conflict with
+// the `FnMut(&T) -> bool` impl above under Rust's coherence rules (nothing
+// stops a hypothetical type implementing both traits), so single-value
+// matching is instead provided per concrete type for the common primitives.
+macro_rules! impl_split_pattern_value {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SplitPattern<$t> for $t {
+                fn matches(&mut self, item: &$t) -> bool {
+                    item == self
+                }
+            }
+        )*
+    };
+}
+
+impl_split_pattern_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+
+/// Marker for `SplitPattern`s that give the same result no matter which
+/// direction elements are fed to `matches` in. Stateless patterns (closures,
+/// single-value matches) qualify; `Subsequence` does not, since it's a
+/// small forward-only automaton that would be evaluated against elements in
+/// the wrong order if scanned backwards. Only `ReversibleSplitPattern`s can
+/// be used with `DoubleEndedIterator` (`.rev()`) or with `rsplit_before`/
+/// `rsplit_after`, both of which scan from the end of the slice.
+pub trait ReversibleSplitPattern<T>: SplitPattern<T> {}
+
+impl <T, F> ReversibleSplitPattern<T> for F
+where
+    F: FnMut(&T) -> bool,
+{}
+
+macro_rules! impl_reversible_split_pattern_value {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl ReversibleSplitPattern<$t> for $t {}
+        )*
+    };
+}
+
+impl_reversible_split_pattern_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+
+/// A multi-element separator for `SplitPattern`, matching a contiguous
+/// window of the input equal to `pattern`. For example:
+///
+/// ```
+/// use slice_ext::*;
+///
+/// let a: &[u8] = &[0, 1, 2, 3, 1, 2, 4];
+/// let sep: &[u8] = &[1, 2];
+/// let mut s = (&a[..]).split_after(Subsequence::new(sep));
+///
+/// assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+/// assert_eq!(s.next().unwrap(), &[3, 1, 2]);
+/// assert_eq!(s.next().unwrap(), &[4]);
+/// assert_eq!(s.next().is_none(), true);
+///
+/// ```
+pub struct Subsequence<'p, T> {
+    pattern: &'p [T],
+    pos: usize,
+}
+
+impl <'p, T> Subsequence<'p, T> {
+    pub fn new(pattern: &'p [T]) -> Self {
+        Subsequence{ pattern, pos: 0 }
+    }
+}
+
+impl <'p, T> SplitPattern<T> for Subsequence<'p, T>
+where
+    T: PartialEq,
+{
+    fn matches(&mut self, item: &T) -> bool {
+        if self.pattern.is_empty() {
+            return false
+        }
+
+        // Advance if this item continues the current partial match, restart
+        // if it instead begins a fresh one, otherwise drop back to empty.
+        // Note: this doesn't handle a separator whose prefix repeats inside
+        // it the way a full KMP search would (e.g. "aab" is not found in
+        // "aaab", as the third 'a' restarts the match rather than extending it).
+        if *item == self.pattern[self.pos] {
+            self.pos += 1;
+        } else if *item == self.pattern[0] {
+            self.pos = 1;
+        } else {
+            self.pos = 0;
+        }
+
+        if self.pos == self.pattern.len() {
+            self.pos = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn match_len(&self) -> usize {
+        self.pattern.len()
+    }
+}
+
 /// SplitBefore trait returns an iterator splitting a slice before a predicate
 /// and including the matched item at the start of the next set (if found).
 /// For example:
@@ -7,7 +140,7 @@
 /// use slice_ext::*;
 /// 
 /// let a: &[u8] = &[0, 1, 2]; 
-/// let mut s = (&a[..]).split_before(|v| *v == 1 );
+/// let mut s = (&a[..]).split_before(|v: &u8| *v == 1 );
 /// 
 /// assert_eq!(s.next().unwrap(), &[0]);
 /// assert_eq!(s.next().unwrap(), &[1, 2]);
@@ -16,16 +149,37 @@
 /// ```
 pub trait SplitBefore<'a, T: 'a, P> {
     fn split_before(&self, predicate: P) -> SplitInc<'a, T, P>;
+
+    /// As `split_before`, but yields at most `n` subslices, with the final
+    /// subslice containing the entire unsplit remainder of the input.
+    /// For example:
+    ///
+    /// ```
+    /// use slice_ext::*;
+    ///
+    /// let a: &[u8] = &[0, 1, 2, 1, 3];
+    /// let mut s = (&a[..]).splitn_before(2, |v: &u8| *v == 1 );
+    ///
+    /// assert_eq!(s.next().unwrap(), &[0]);
+    /// assert_eq!(s.next().unwrap(), &[1, 2, 1, 3]);
+    /// assert_eq!(s.next().is_none(), true);
+    ///
+    /// ```
+    fn splitn_before(&self, n: usize, predicate: P) -> SplitInc<'a, T, P>;
 }
 
-impl <'a, T: 'a, P> SplitBefore<'a, T, P> for &'a [T] 
+impl <'a, T: 'a, P> SplitBefore<'a, T, P> for &'a [T]
 where
-    P: FnMut(&T) -> bool,
+    P: SplitPattern<T>,
     T: core::fmt::Debug,
 {
     fn split_before(&self, predicate: P) -> SplitInc<'a, T, P> {
         SplitInc::split_before(&self, predicate)
     }
+
+    fn splitn_before(&self, n: usize, predicate: P) -> SplitInc<'a, T, P> {
+        SplitInc::splitn_before(n, &self, predicate)
+    }
 }
 
 /// SplitAfter trait returns an iterator splitting a slice after a predicate
@@ -36,7 +190,7 @@ where
 /// use slice_ext::*;
 /// 
 /// let a: &[u8] = &[0, 1, 2]; 
-/// let mut s = (&a[..]).split_after(|v| *v == 1 );
+/// let mut s = (&a[..]).split_after(|v: &u8| *v == 1 );
 /// 
 /// assert_eq!(s.next().unwrap(), &[0, 1]);
 /// assert_eq!(s.next().unwrap(), &[2]);
@@ -45,75 +199,178 @@ where
 /// ```
 pub trait SplitAfter<'a, T: 'a, P> {
     fn split_after(&self, predicate: P) -> SplitInc<'a, T, P>;
+
+    /// As `split_after`, but yields at most `n` subslices, with the final
+    /// subslice containing the entire unsplit remainder of the input. This
+    /// is the common "split on the first delimiter, keep the tail intact"
+    /// pattern, e.g. parsing `KEY=rest-with-=-signs`. For example:
+    ///
+    /// ```
+    /// use slice_ext::*;
+    ///
+    /// let a: &[u8] = &[0, 1, 2, 1, 3];
+    /// let mut s = (&a[..]).splitn_after(2, |v: &u8| *v == 1 );
+    ///
+    /// assert_eq!(s.next().unwrap(), &[0, 1]);
+    /// assert_eq!(s.next().unwrap(), &[2, 1, 3]);
+    /// assert_eq!(s.next().is_none(), true);
+    ///
+    /// ```
+    fn splitn_after(&self, n: usize, predicate: P) -> SplitInc<'a, T, P>;
 }
 
-impl <'a, T: 'a, P> SplitAfter<'a, T, P> for &'a [T] 
+impl <'a, T: 'a, P> SplitAfter<'a, T, P> for &'a [T]
 where
-    P: FnMut(&T) -> bool,
+    P: SplitPattern<T>,
     T: core::fmt::Debug,
 {
     fn split_after(&self, predicate: P) -> SplitInc<'a, T, P> {
         SplitInc::split_after(&self, predicate)
     }
+
+    fn splitn_after(&self, n: usize, predicate: P) -> SplitInc<'a, T, P> {
+        SplitInc::splitn_after(n, &self, predicate)
+    }
+}
+
+/// RSplitBefore searches for matches starting at the end of the slice and
+/// yields subslices in reverse order, mirroring std's `[T]::rsplit`. It
+/// yields the same subslices as `split_before(..).rev()`; no count-limited
+/// `rsplitn_before` variant is provided. For example:
+///
+/// ```
+/// use slice_ext::*;
+///
+/// let a: &[u8] = &[0, 1, 2];
+/// let mut s = (&a[..]).rsplit_before(|v: &u8| *v == 1 );
+///
+/// assert_eq!(s.next().unwrap(), &[1, 2]);
+/// assert_eq!(s.next().unwrap(), &[0]);
+/// assert_eq!(s.next().is_none(), true);
+///
+/// ```
+pub trait RSplitBefore<'a, T: 'a, P> {
+    fn rsplit_before(&self, predicate: P) -> SplitInc<'a, T, P>;
+}
+
+impl <'a, T: 'a, P> RSplitBefore<'a, T, P> for &'a [T]
+where
+    P: ReversibleSplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    fn rsplit_before(&self, predicate: P) -> SplitInc<'a, T, P> {
+        SplitInc::rsplit_before(&self, predicate)
+    }
+}
+
+/// RSplitAfter searches for matches starting at the end of the slice and
+/// yields subslices in reverse order, mirroring std's `[T]::rsplit`. For
+/// example:
+///
+/// ```
+/// use slice_ext::*;
+///
+/// let a: &[u8] = &[0, 1, 2];
+/// let mut s = (&a[..]).rsplit_after(|v: &u8| *v == 1 );
+///
+/// assert_eq!(s.next().unwrap(), &[2]);
+/// assert_eq!(s.next().unwrap(), &[0, 1]);
+/// assert_eq!(s.next().is_none(), true);
+///
+/// ```
+pub trait RSplitAfter<'a, T: 'a, P> {
+    fn rsplit_after(&self, predicate: P) -> SplitInc<'a, T, P>;
+}
+
+impl <'a, T: 'a, P> RSplitAfter<'a, T, P> for &'a [T]
+where
+    P: ReversibleSplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    fn rsplit_after(&self, predicate: P) -> SplitInc<'a, T, P> {
+        SplitInc::rsplit_after(&self, predicate)
+    }
 }
 
 pub struct SplitInc<'a, T: 'a, F> {
     index: usize,
+    end: usize,
     data: &'a [T],
     matcher: F,
     mode: Mode,
+    // Number of subslices still to be yielded, for the `splitn_*` variants.
+    // `None` means unlimited (the plain `split_before`/`split_after` case).
+    limit: Option<usize>,
 }
 
 enum Mode {
     Before,
     After,
+    // Reversed search direction for `rsplit_before`/`rsplit_after`: matches
+    // are searched for from the end of the slice, and chunks are yielded in
+    // reverse order.
+    RBefore,
+    RAfter,
 }
 
-impl <'a, T, F> SplitInc<'a, T, F> 
-where 
-    F: FnMut(&T) -> bool,
+impl <'a, T, F> SplitInc<'a, T, F>
+where
+    F: SplitPattern<T>,
     T: core::fmt::Debug,
 {
     pub fn split_before(data: &'a [T], matcher: F) -> Self {
-        SplitInc{ index: 0, data, matcher, mode: Mode::Before }
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::Before, limit: None }
     }
 
     pub fn split_after(data: &'a [T], matcher: F) -> Self {
-        SplitInc{ index: 0, data, matcher, mode: Mode::After }
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::After, limit: None }
+    }
+
+    pub fn splitn_before(n: usize, data: &'a [T], matcher: F) -> Self {
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::Before, limit: Some(n) }
+    }
+
+    pub fn splitn_after(n: usize, data: &'a [T], matcher: F) -> Self {
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::After, limit: Some(n) }
     }
 
     fn iter_before(&mut self) -> Option<&'a [T]> {
         // Short circuit on completion
-        if self.index == self.data.len() {
+        if self.index == self.end {
             return None
         }
 
         // Select search range
         let index = self.index;
 
-        for i in index..self.data.len() {
+        for i in index..self.end {
+
+            if self.matcher.matches(&self.data[i]) {
+                // The match's window may span several elements (see
+                // `SplitPattern::match_len`); the split point is the start
+                // of that window, not the element `i` that completed it.
+                let start = i + 1 - self.matcher.match_len();
 
-            if (self.matcher)(&self.data[i]) {
                 // If our match is in the first position, and we're not at the end,
                 // continue searching
-                if i == index && i < self.data.len() - 1 {
+                if start == index && i < self.end - 1 {
                     continue
                 // If our match is in the first position, and we are at the end,
                 // return the last entry
-                } else if i == index {
-                    self.index = self.data.len();
-                    return Some(&self.data[index..])
+                } else if start == index {
+                    self.index = self.end;
+                    return Some(&self.data[index..self.end])
                 }
 
                 // When a match is found, update the count and return preceding data
-                self.index = i;
-                return Some(&self.data[index..i])
+                self.index = start;
+                return Some(&self.data[index..start])
             }
 
              // When we're out of data, return anything left
-            if i == (self.data.len() - 1) {
-                self.index = self.data.len();
-                return Some(&self.data[index..])
+            if i == self.end - 1 {
+                self.index = self.end;
+                return Some(&self.data[index..self.end])
             }
         }
 
@@ -122,44 +379,323 @@ where
 
     fn iter_after(&mut self) -> Option<&'a [T]> {
         // Short circuit on completion
-        if self.index == self.data.len() {
+        if self.index == self.end {
             return None
         }
 
         // Select search range
         let index = self.index;
 
-        for i in index..self.data.len() {
+        for i in index..self.end {
 
             // When a match is found, update the count and return preceding data
-            if (self.matcher)(&self.data[i]) {
+            if self.matcher.matches(&self.data[i]) {
                 self.index = i+1;
                 return Some(&self.data[index..i+1])
             }
 
             // When we're out of data, return anything left
-            if i == (self.data.len() - 1) {
-                self.index = self.data.len();
-                return Some(&self.data[index..])
+            if i == self.end - 1 {
+                self.index = self.end;
+                return Some(&self.data[index..self.end])
+            }
+        }
+
+        None
+    }
+
+    // Mirrors `iter_before`, scanning backwards from `end` towards `index` so
+    // that forward and backward iteration meet cleanly in the middle.
+    fn iter_before_back(&mut self) -> Option<&'a [T]> {
+        // Short circuit on completion
+        if self.index == self.end {
+            return None
+        }
+
+        let end = self.end;
+
+        // A match at `index` itself doesn't start a new chunk (it is already
+        // the first element of the chunk beginning at `index`), so only
+        // matches strictly after `index` can end this chunk.
+        for j in (self.index + 1..end).rev() {
+            if self.matcher.matches(&self.data[j]) {
+                let start = j + 1 - self.matcher.match_len();
+                self.end = start;
+                return Some(&self.data[start..end])
             }
         }
 
+        // No further matches, the rest of the range is the last chunk
+        self.end = self.index;
+        Some(&self.data[self.index..end])
+    }
+
+    // Shared by `Iterator::next` and `DoubleEndedIterator::next_back`: for
+    // the count-limited `splitn_*` variants, once a single subslice remains
+    // to be yielded it consumes the whole remainder in one shot, regardless
+    // of which end asked for it (RFC 979 semantics: `n` bounds the number of
+    // yielded items, not the number of splits performed, and applies the
+    // same whether the items are pulled from the front or the back).
+    // Returns `Some(result)` to short-circuit the caller, or `None` when
+    // there's no limit in play or more than one subslice may still be
+    // yielded (the count is decremented first), in which case the caller
+    // should perform its normal forward/backward split.
+    fn take_limited(&mut self) -> Option<Option<&'a [T]>> {
+        let remaining = self.limit?;
+
+        if remaining == 0 {
+            return Some(None)
+        }
+        if remaining == 1 {
+            if self.index == self.end {
+                return Some(None)
+            }
+            self.limit = Some(0);
+            let chunk = &self.data[self.index..self.end];
+            self.index = self.end;
+            return Some(Some(chunk))
+        }
+
+        self.limit = Some(remaining - 1);
         None
     }
+
+    // Mirrors `iter_after`, scanning backwards from `end` towards `index`.
+    fn iter_after_back(&mut self) -> Option<&'a [T]> {
+        // Short circuit on completion
+        if self.index == self.end {
+            return None
+        }
+
+        let end = self.end;
+
+        for j in (self.index..end - 1).rev() {
+            if self.matcher.matches(&self.data[j]) {
+                self.end = j + 1;
+                return Some(&self.data[j + 1..end])
+            }
+        }
+
+        // No further matches, the rest of the range is the last chunk
+        self.end = self.index;
+        Some(&self.data[self.index..end])
+    }
 }
 
-impl <'a, T, F> Iterator for SplitInc<'a, T, F> 
-where 
-    F: FnMut(&T) -> bool,
+// `rsplit_before`/`rsplit_after` scan from the end of the slice, matching
+// elements in reverse order. A `ReversibleSplitPattern` bound is required
+// (rather than the plain `SplitPattern` used above) because a stateful,
+// multi-element pattern like `Subsequence` is a small forward-only automaton:
+// feeding it elements back-to-front would silently match against the wrong
+// order and produce incorrect splits instead of a compile error.
+impl <'a, T, F> SplitInc<'a, T, F>
+where
+    F: ReversibleSplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    pub fn rsplit_before(data: &'a [T], matcher: F) -> Self {
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::RBefore, limit: None }
+    }
+
+    pub fn rsplit_after(data: &'a [T], matcher: F) -> Self {
+        SplitInc{ index: 0, end: data.len(), data, matcher, mode: Mode::RAfter, limit: None }
+    }
+}
+
+impl <'a, T, F> Iterator for SplitInc<'a, T, F>
+where
+    F: SplitPattern<T>,
     T: core::fmt::Debug,
 {
     type Item = &'a [T];
-    
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if let Some(result) = self.take_limited() {
+            return result
+        }
+
+        match self.mode {
+            Mode::Before => self.iter_before(),
+            Mode::After => self.iter_after(),
+            Mode::RBefore => self.iter_before_back(),
+            Mode::RAfter => self.iter_after_back(),
+        }
+    }
+}
+
+/// DoubleEndedIterator support allows `SplitInc` (and thus `split_before`/
+/// `split_after`) to be reversed, e.g. `slice.split_after(pred).rev()`. For
+/// the reversed `rsplit_before`/`rsplit_after` modes this runs in the
+/// opposite direction, so reversing an `rsplit_*` iterator recovers the
+/// original front-to-back split order. For the count-limited `splitn_*`
+/// variants, `next_back` shares the same `limit` counter as `next` (see
+/// `take_limited`), so mixing `next()`/`next_back()` calls on a `splitn_*`
+/// iterator still yields at most `n` items in total.
+impl <'a, T, F> DoubleEndedIterator for SplitInc<'a, T, F>
+where
+    F: ReversibleSplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(result) = self.take_limited() {
+            return result
+        }
+
+        match self.mode {
+            Mode::Before => self.iter_before_back(),
+            Mode::After => self.iter_after_back(),
+            Mode::RBefore => self.iter_before(),
+            Mode::RAfter => self.iter_after(),
+        }
+    }
+}
+
+/// SplitBeforeMut mirrors `SplitBefore`, yielding mutable subslices so
+/// delimited records can be edited in place.
+pub trait SplitBeforeMut<'a, T: 'a, P> {
+    fn split_before_mut(self, predicate: P) -> SplitIncMut<'a, T, P>;
+}
+
+impl <'a, T: 'a, P> SplitBeforeMut<'a, T, P> for &'a mut [T]
+where
+    P: SplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    fn split_before_mut(self, predicate: P) -> SplitIncMut<'a, T, P> {
+        SplitIncMut::split_before(self, predicate)
+    }
+}
+
+/// SplitAfterMut mirrors `SplitAfter`, yielding mutable subslices so
+/// delimited records can be edited in place. For example:
+///
+/// ```
+/// use slice_ext::*;
+///
+/// let a: &mut [u8] = &mut [0, 1, 2];
+/// let mut s = (&mut a[..]).split_after_mut(|v: &u8| *v == 1 );
+///
+/// assert_eq!(s.next().unwrap(), &mut [0, 1]);
+/// assert_eq!(s.next().unwrap(), &mut [2]);
+/// assert_eq!(s.next().is_none(), true);
+///
+/// ```
+pub trait SplitAfterMut<'a, T: 'a, P> {
+    fn split_after_mut(self, predicate: P) -> SplitIncMut<'a, T, P>;
+}
+
+impl <'a, T: 'a, P> SplitAfterMut<'a, T, P> for &'a mut [T]
+where
+    P: SplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    fn split_after_mut(self, predicate: P) -> SplitIncMut<'a, T, P> {
+        SplitIncMut::split_after(self, predicate)
+    }
+}
+
+pub struct SplitIncMut<'a, T: 'a, F> {
+    // The remaining unsplit data, taken (via `Option::take`) and carved into
+    // non-aliasing head/tail halves with `split_at_mut` on each `next()`.
+    data: Option<&'a mut [T]>,
+    matcher: F,
+    mode: Mode,
+}
+
+impl <'a, T, F> SplitIncMut<'a, T, F>
+where
+    F: SplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    pub fn split_before(data: &'a mut [T], matcher: F) -> Self {
+        SplitIncMut{ data: Some(data), matcher, mode: Mode::Before }
+    }
+
+    pub fn split_after(data: &'a mut [T], matcher: F) -> Self {
+        SplitIncMut{ data: Some(data), matcher, mode: Mode::After }
+    }
+
+    fn iter_before(&mut self) -> Option<&'a mut [T]> {
+        let data = self.data.take()?;
+
+        if data.is_empty() {
+            return None
+        }
+
+        let len = data.len();
+        let mut split_at = None;
+
+        for i in 0..len {
+            if self.matcher.matches(&data[i]) {
+                let start = i + 1 - self.matcher.match_len();
+
+                // If our match is in the first position, and we're not at the end,
+                // continue searching
+                if start == 0 && i < len - 1 {
+                    continue
+                // If our match is in the first position, and we are at the end,
+                // return the last entry
+                } else if start == 0 {
+                    return Some(data)
+                }
+
+                split_at = Some(start);
+                break
+            }
+        }
+
+        match split_at {
+            Some(i) => {
+                let (head, tail) = data.split_at_mut(i);
+                self.data = Some(tail);
+                Some(head)
+            }
+            None => Some(data),
+        }
+    }
+
+    fn iter_after(&mut self) -> Option<&'a mut [T]> {
+        let data = self.data.take()?;
+
+        if data.is_empty() {
+            return None
+        }
+
+        let len = data.len();
+        let mut split_at = None;
+
+        for i in 0..len {
+            if self.matcher.matches(&data[i]) {
+                split_at = Some(i);
+                break
+            }
+        }
+
+        match split_at {
+            Some(i) => {
+                let (head, tail) = data.split_at_mut(i + 1);
+                self.data = Some(tail);
+                Some(head)
+            }
+            None => Some(data),
+        }
+    }
+}
+
+impl <'a, T, F> Iterator for SplitIncMut<'a, T, F>
+where
+    F: SplitPattern<T>,
+    T: core::fmt::Debug,
+{
+    type Item = &'a mut [T];
+
     fn next(&mut self) -> Option<Self::Item> {
-    
         match self.mode {
             Mode::Before => self.iter_before(),
             Mode::After => self.iter_after(),
+            // `SplitIncMut` is only ever constructed with `Mode::Before`/`Mode::After`.
+            Mode::RBefore | Mode::RAfter => unreachable!(),
         }
     }
 }
@@ -172,7 +708,7 @@ mod tests {
     fn test_split_before() {
         let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
         
-        let mut s = (&a[..]).split_before(|v| *v == 2 || *v == 5);
+        let mut s = (&a[..]).split_before(|v: &u8| *v == 2 || *v == 5);
         
         assert_eq!(s.next().unwrap(), &[0, 1]);
         assert_eq!(s.next().unwrap(), &[2, 3, 4]);
@@ -184,7 +720,7 @@ mod tests {
     fn test_split_before_no_match() {
         let a: &[u8] = &[0, 1, 2];
         
-        let mut s = SplitInc::split_before(&a, |v| *v == 12);
+        let mut s = SplitInc::split_before(&a, |v: &u8| *v == 12);
         
         assert_eq!(s.next().unwrap(), &[0, 1, 2]);
         assert_eq!(s.next().is_none(), true);
@@ -194,7 +730,7 @@ mod tests {
     fn test_split_before_start() {
         let a: &[u8] = &[0, 1, 2];
         
-        let mut s = SplitInc::split_before(&a, |v| *v == 0 );
+        let mut s = SplitInc::split_before(&a, |v: &u8| *v == 0 );
         
         assert_eq!(s.next().unwrap(), &[0, 1, 2]);
         assert_eq!(s.next().is_none(), true);
@@ -204,7 +740,7 @@ mod tests {
     fn test_split_before_end() {
         let a: &[u8] = &[0, 1, 2];
         
-        let mut s = SplitInc::split_before(&a, |v| *v == 2 );
+        let mut s = SplitInc::split_before(&a, |v: &u8| *v == 2 );
         
         assert_eq!(s.next().unwrap(), &[0, 1]);
         assert_eq!(s.next().unwrap(), &[2]);
@@ -215,7 +751,7 @@ mod tests {
     fn test_split_after() {
         let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
         
-        let mut s = SplitInc::split_after(&a, |v| *v == 2 || *v == 5);
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 2 || *v == 5);
         
         assert_eq!(s.next().unwrap(), &[0, 1, 2]);
         assert_eq!(s.next().unwrap(), &[3, 4, 5]);
@@ -227,7 +763,7 @@ mod tests {
     fn test_split_after_no_match() {
         let a: &[u8] = &[0, 1, 2];
         
-        let mut s = SplitInc::split_after(&a, |v| *v == 12);
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 12);
         
         assert_eq!(s.next().unwrap(), &[0, 1, 2]);
         assert_eq!(s.next().is_none(), true);
@@ -237,7 +773,7 @@ mod tests {
     fn test_split_after_start() {
         let a: &[u8] = &[0, 1, 2];
         
-        let mut s = SplitInc::split_after(&a, |v| *v == 0 );
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 0 );
         
         assert_eq!(s.next().unwrap(), &[0]);
         assert_eq!(s.next().unwrap(), &[1, 2]);
@@ -247,9 +783,307 @@ mod tests {
     #[test]
     fn test_split_after_end() {
         let a: &[u8] = &[0, 1, 2];
-        
-        let mut s = SplitInc::split_after(&a, |v| *v == 2 );
-        
+
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 2 );
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_before_rev() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::split_before(&a, |v: &u8| *v == 2 || *v == 5).rev();
+
+        assert_eq!(s.next().unwrap(), &[5, 6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[2, 3, 4]);
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_rev() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 2 || *v == 5).rev();
+
+        assert_eq!(s.next().unwrap(), &[6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[3, 4, 5]);
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_rev_no_match() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut before = SplitInc::split_before(&a, |v: &u8| *v == 12).rev();
+        assert_eq!(before.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(before.next().is_none(), true);
+
+        let mut after = SplitInc::split_after(&a, |v: &u8| *v == 12).rev();
+        assert_eq!(after.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(after.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_before() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::splitn_before(2, &a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().unwrap(), &[2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_after() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::splitn_after(2, &a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().unwrap(), &[3, 4, 5, 6, 7, 8]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_one_yields_whole_slice() {
+        let a: &[u8] = &[0, 1, 2, 3];
+
+        let mut s = SplitInc::splitn_after(1, &a, |v: &u8| *v == 1);
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2, 3]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_exceeds_match_count() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::splitn_after(5, &a, |v: &u8| *v == 1);
+
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().unwrap(), &[2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_after_rev() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::splitn_after(2, &a, |v: &u8| *v == 2 || *v == 5).rev();
+
+        assert_eq!(s.next().unwrap(), &[6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_splitn_before_mixed_next_and_next_back() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::splitn_before(2, &a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next_back().unwrap(), &[2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(s.next().is_none(), true);
+        assert_eq!(s.next_back().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_meet_in_middle() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::split_after(&a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next_back().unwrap(), &[6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[3, 4, 5]);
+        assert_eq!(s.next().is_none(), true);
+        assert_eq!(s.next_back().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_before() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::rsplit_before(&a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[5, 6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[2, 3, 4]);
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_before_no_match() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_before(&a, |v: &u8| *v == 12);
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_before_match_at_start() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_before(&a, |v: &u8| *v == 0 );
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_before_match_at_end() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_before(&a, |v: &u8| *v == 2 );
+
+        assert_eq!(s.next().unwrap(), &[2]);
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_after() {
+        let a: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitInc::rsplit_after(&a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &[6, 7, 8]);
+        assert_eq!(s.next().unwrap(), &[3, 4, 5]);
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_after_no_match() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_after(&a, |v: &u8| *v == 12);
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_after_match_at_start() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_after(&a, |v: &u8| *v == 0 );
+
+        assert_eq!(s.next().unwrap(), &[1, 2]);
+        assert_eq!(s.next().unwrap(), &[0]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_rsplit_after_match_at_end() {
+        let a: &[u8] = &[0, 1, 2];
+
+        let mut s = SplitInc::rsplit_after(&a, |v: &u8| *v == 2 );
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_before_mut() {
+        let a: &mut [u8] = &mut [0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitIncMut::split_before(a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &mut [0, 1]);
+        assert_eq!(s.next().unwrap(), &mut [2, 3, 4]);
+        assert_eq!(s.next().unwrap(), &mut [5, 6, 7, 8]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_mut() {
+        let a: &mut [u8] = &mut [0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut s = SplitIncMut::split_after(a, |v: &u8| *v == 2 || *v == 5);
+
+        assert_eq!(s.next().unwrap(), &mut [0, 1, 2]);
+        assert_eq!(s.next().unwrap(), &mut [3, 4, 5]);
+        assert_eq!(s.next().unwrap(), &mut [6, 7, 8]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_mut_no_match() {
+        let a: &mut [u8] = &mut [0, 1, 2];
+
+        let mut s = SplitIncMut::split_after(a, |v: &u8| *v == 12);
+
+        assert_eq!(s.next().unwrap(), &mut [0, 1, 2]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_before_mut_edits_in_place() {
+        let a: &mut [u8] = &mut [0, 1, 2, 3, 4, 5];
+
+        for chunk in (&mut a[..]).split_before_mut(|v: &u8| *v == 3) {
+            for v in chunk.iter_mut() {
+                *v *= 10;
+            }
+        }
+
+        assert_eq!(a, &mut [0, 10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn test_split_after_value_pattern() {
+        let a: &[u8] = &[0, 1, 2, 1, 3];
+
+        let mut s = SplitInc::split_after(&a, 1u8);
+
+        assert_eq!(s.next().unwrap(), &[0, 1]);
+        assert_eq!(s.next().unwrap(), &[2, 1]);
+        assert_eq!(s.next().unwrap(), &[3]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_before_subsequence_pattern() {
+        let a: &[u8] = &[0, 1, 2, 3, 1, 2, 4];
+        let sep: &[u8] = &[1, 2];
+
+        let mut s = SplitInc::split_before(&a, Subsequence::new(sep));
+
+        assert_eq!(s.next().unwrap(), &[0]);
+        assert_eq!(s.next().unwrap(), &[1, 2, 3]);
+        assert_eq!(s.next().unwrap(), &[1, 2, 4]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_subsequence_pattern() {
+        let a: &[u8] = &[0, 1, 2, 3, 1, 2, 4];
+        let sep: &[u8] = &[1, 2];
+
+        let mut s = SplitInc::split_after(&a, Subsequence::new(sep));
+
+        assert_eq!(s.next().unwrap(), &[0, 1, 2]);
+        assert_eq!(s.next().unwrap(), &[3, 1, 2]);
+        assert_eq!(s.next().unwrap(), &[4]);
+        assert_eq!(s.next().is_none(), true);
+    }
+
+    #[test]
+    fn test_split_after_subsequence_pattern_no_match() {
+        let a: &[u8] = &[0, 1, 2];
+        let sep: &[u8] = &[9, 9];
+
+        let mut s = SplitInc::split_after(&a, Subsequence::new(sep));
+
         assert_eq!(s.next().unwrap(), &[0, 1, 2]);
         assert_eq!(s.next().is_none(), true);
     }